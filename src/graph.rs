@@ -0,0 +1,102 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+/**
+A directed graph describing how a game's areas connect to one another.
+
+Each node is an area's RESREF and each edge points from an area to an area
+which can be traveled to from it, whether via a travel region in the ARE file
+or a link on the worldmap.
+
+Area names are stored in upper case since the games do not treat them as case
+sensitive.
+*/
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AreaGraph
+{
+	pub edges: BTreeMap<String, BTreeSet<String>>,
+	pub warnings: Vec<String>,
+}
+
+impl AreaGraph
+{
+	/**
+	Add an area to the graph without any connections.
+	
+	## Parameters
+	
+	- **area** - The name of the area being added.
+	*/
+	pub fn addArea(&mut self, area: String)
+	{
+		self.edges.entry(area.to_uppercase()).or_default();
+	}
+	
+	/**
+	Add a directed connection between two areas, adding either area to the
+	graph if necessary.
+	
+	## Parameters
+	
+	- **from** - The name of the area from which travel begins.
+	- **to** - The name of the area to which travel leads.
+	*/
+	pub fn addEdge(&mut self, from: String, to: String)
+	{
+		self.addArea(to.to_owned());
+		self.edges.entry(from.to_uppercase())
+			.or_default()
+			.insert(to.to_uppercase());
+	}
+	
+	/**
+	Determine whether a directed connection exists between two areas.
+	
+	## Parameters
+	
+	- **from** - The name of the area from which travel begins.
+	- **to** - The name of the area to which travel leads.
+	*/
+	pub fn containsEdge(&self, from: String, to: String) -> bool
+	{
+		return match self.edges.get(&from.to_uppercase())
+		{
+			Some(destinations) => destinations.contains(&to.to_uppercase()),
+			None => false,
+		};
+	}
+	
+	/**
+	Retrieve the names of the areas which can be traveled to from an area.
+	
+	## Parameters
+	
+	- **area** - The name of the area from which travel begins.
+	*/
+	pub fn destinations(&self, area: String) -> Vec<String>
+	{
+		return match self.edges.get(&area.to_uppercase())
+		{
+			Some(destinations) => destinations.iter().cloned().collect(),
+			None => vec![],
+		};
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	
+	#[test]
+	fn AddEdge()
+	{
+		let mut graph = AreaGraph::default();
+		graph.addEdge("ar2600".to_string(), "AR2601".to_string());
+		
+		assert!(graph.containsEdge("AR2600".to_string(), "ar2601".to_string()));
+		assert!(!graph.containsEdge("AR2601".to_string(), "AR2600".to_string()));
+		assert_eq!(2, graph.edges.len());
+		assert_eq!(vec!["AR2601".to_string()], graph.destinations("AR2600".to_string()));
+		assert!(graph.destinations("AR2601".to_string()).is_empty());
+	}
+}
@@ -1,5 +1,6 @@
 mod bits;
 mod bytes;
+mod graph;
 mod platform;
 mod resource;
 #[cfg(test)]
@@ -41,6 +42,12 @@ pub fn LoadResource(game: i32, resourceType: i16, resourceName: char_p::Ref<'_>)
 	return result;
 }
 
+#[ffi_export]
+pub fn LoadTileCount(game: i32, tilesetName: char_p::Ref<'_>) -> u32
+{
+	return TileCount(game, tilesetName.to_string());
+}
+
 #[ffi_export]
 pub fn ResourceDimensions(game: i32, resourceType: i16, resourceName: char_p::Ref<'_>) -> Dimensions
 {
@@ -120,6 +127,22 @@ fn SizeBmp(game: i32, name: String) -> usize
 	return size;
 }
 
+fn TileCount(game: i32, name: String) -> u32
+{
+	let mut count = 0;
+	if let Ok(resourceManager) = getManager().lock()
+	{
+		if let Some(tis) = resourceManager.loadTileset(
+			Games::from_repr(game.to_owned()).unwrap_or(Games::None),
+			name.to_owned())
+		{
+			count = tis.tileCount;
+		}
+	}
+	
+	return count;
+}
+
 #[cfg(test)]
 mod tests
 {
@@ -210,6 +233,28 @@ mod tests
 		drop(name);
 	}
 	
+	#[test]
+	fn TestLoadTileCount()
+	{
+		//Load a file resource.
+		if let Ok(resourceManager) = getManager().lock()
+		{
+			let _ = updateResourceManager(&resourceManager);
+		}
+		
+		let game = Games::BaldursGate1;
+		let name = char_p::new("AR2600");
+		
+		let result = LoadTileCount(game as i32, name.as_ref());
+		assert!(result > 0);
+		
+		if let Ok(resourceManager) = getManager().lock()
+		{
+			let expected = resourceManager.loadTilesetTiles(game, name.to_string()).unwrap();
+			assert_eq!(expected.len(), result as usize);
+		}
+	}
+	
 	#[test]
 	fn TestResourceDimensions()
 	{
@@ -77,6 +77,33 @@ pub fn KeyFileName(game: Games) -> Option<String>
 	return map.get(&game).cloned();
 }
 
+/**
+Files, relative to the installation directory, whose presence identifies a
+game's installation.
+
+Paths use '\' as the separator, matching the BIF file names stored in KEY
+files. Every file in a game's list must be present for that game to be
+considered a match.
+
+Only data files are listed since executables differ between platforms.
+*/
+pub fn SignatureFiles(game: Games) -> Option<Vec<String>>
+{
+	let map = HashMap::from([
+		( Games::BaldursGate1, vec![ String::from("baldur.ini") ] ),
+		( Games::BaldursGate1EnhancedEdition, vec![ String::from("engine.lua"), String::from("lang"), String::from("data\\AREA000A.bif") ] ),
+		( Games::BaldursGate2, vec![ String::from("baldur.ini"), String::from("data\\25Areas.bif") ] ),
+		( Games::BaldursGate2EnhancedEdition, vec![ String::from("engine.lua"), String::from("lang"), String::from("data\\25Areas.bif") ] ),
+		( Games::IcewindDale1, vec![ String::from("icewind.ini") ] ),
+		( Games::IcewindDale1EnhancedEdition, vec![ String::from("engine.lua"), String::from("lang"), String::from("data\\ARIWD.bif") ] ),
+		( Games::IcewindDale2, vec![ String::from("icewind2.ini") ] ),
+		( Games::PlanescapeTorment, vec![ String::from("torment.ini") ] ),
+		( Games::PlanescapeTormentEnhancedEdition, vec![ String::from("engine.lua"), String::from("lang"), String::from("data\\AR0200.bif") ] ),
+	]);
+	
+	return map.get(&game).cloned();
+}
+
 #[allow(dead_code)]
 pub fn SteamAppId(game: Games) -> Option<u32>
 {
@@ -1,6 +1,6 @@
 mod global;
 
-pub use global::{Games, KeyFileName};
+pub use global::{Games, KeyFileName, SignatureFiles};
 
 #[cfg(target_os = "linux")]
 mod linux;
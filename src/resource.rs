@@ -1,10 +1,12 @@
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::io::Cursor;
 use std::path::{Path, PathBuf};
+use ::anyhow::{Context, Result};
 use ::glob::glob;
-use crate::platform::{Games, KeyFileName};
-use crate::types::{ResourceType_TIS, Are, Bif, InfinityEngineType, Key, Readable, Tis, Tlk, ReadFromFile};
+use crate::graph::AreaGraph;
+use crate::platform::{Games, KeyFileName, SignatureFiles};
+use crate::types::{ResourceType_ARE, ResourceType_TIS, ResourceType_WMP, Are, Bif, InfinityEngineType, Key, Readable, Tis, Tlk, ReadFromFile, Wmp};
 
 /**
 A convenient interface for retrieving resources from Infinity Engine game files.
@@ -29,6 +31,134 @@ pub struct ResourceManager
 
 impl ResourceManager
 {
+	/**
+	Add an edge to `graph` for each travel region in each of a `game`'s ARE
+	resources.
+	
+	## Parameters
+	
+	- **game** - The game which identifies the installation path from which to
+		read.
+	- **key** - The `game`'s `Key`.
+	- **graph** - The graph to which edges and warnings are added.
+	
+	## Remarks
+	
+	The ARE resources are grouped by the BIF file which contains them so that
+	each `Bif` is only read once. A `Bif` which is already cached is used in
+	place. Otherwise it is read directly from the file system and is not added
+	to the cache, since area BIFs also contain large tilesets.
+	*/
+	fn addTravelRegionEdges(&self, game: Games, key: &Key, graph: &mut AreaGraph)
+	{
+		let mut entriesByBif = BTreeMap::<u32, Vec<_>>::new();
+		for entry in key.resourceEntries.iter().filter(|entry| entry.r#type == ResourceType_ARE as u16)
+		{
+			entriesByBif.entry(entry.indexBifEntry())
+				.or_default()
+				.push(entry);
+		}
+		
+		for (bifIndex, entries) in entriesByBif
+		{
+			let Some(bifEntry) = key.bifEntries.get(bifIndex as usize) else
+			{
+				graph.warnings.push(format!("Invalid BIF index {} for {} ARE resources", bifIndex, entries.len()));
+				continue;
+			};
+			
+			let resources: Vec<(String, u32)> = entries.iter()
+				.map(|entry| (entry.name.to_owned(), entry.indexFile()))
+				.collect();
+			
+			if let Some(bif) = self.bifs.borrow().get(&game).and_then(|map| map.get(&bifEntry.fileName))
+			{
+				Self::addTravelRegionEdgesFromBif(bif, &resources, graph);
+				continue;
+			}
+			
+			match self.readBifUncached(game, bifEntry.fileName.to_owned())
+			{
+				Ok(bif) => Self::addTravelRegionEdgesFromBif(&bif, &resources, graph),
+				Err(e) => graph.warnings.push(format!("Failed to read BIF {} containing {} ARE resources: {:#}", bifEntry.fileName, resources.len(), e)),
+			}
+		}
+	}
+	
+	/**
+	Add an edge to `graph` for each travel region in each of the named ARE
+	resources contained in `bif`.
+	
+	## Parameters
+	
+	- **bif** - The `Bif` containing the ARE resources.
+	- **resources** - The name and file index of each ARE resource.
+	- **graph** - The graph to which edges and warnings are added.
+	*/
+	fn addTravelRegionEdgesFromBif(bif: &Bif, resources: &[(String, u32)], graph: &mut AreaGraph)
+	{
+		for (name, fileIndex) in resources
+		{
+			if let Some(fileEntry) = bif.fileEntries.iter().find(|fileEntry| fileEntry.index() == *fileIndex)
+			{
+				let mut cursor = Cursor::new(fileEntry.data.clone());
+				match Are::fromCursor(&mut cursor)
+				{
+					Ok(are) => {
+						graph.addArea(name.to_owned());
+						for destination in are.travelDestinations()
+						{
+							graph.addEdge(name.to_owned(), destination);
+						}
+					},
+					Err(e) => graph.warnings.push(format!("Failed to parse ARE resource {}: {:#}", name, e)),
+				}
+			}
+			else
+			{
+				graph.warnings.push(format!("ARE resource {} was not found in its BIF", name));
+			}
+		}
+	}
+	
+	/**
+	Add an edge to `graph` for each area link in each of a `game`'s WMP
+	resources.
+	
+	## Parameters
+	
+	- **game** - The game which identifies the installation path from which to
+		read.
+	- **key** - The `game`'s `Key`.
+	- **graph** - The graph to which edges and warnings are added.
+	*/
+	fn addWorldmapLinkEdges(&self, game: Games, key: &Key, graph: &mut AreaGraph)
+	{
+		for entry in key.resourceEntries.iter().filter(|entry| entry.r#type == ResourceType_WMP as u16)
+		{
+			if let Some(wmp) = self.loadResource::<Wmp>(game, ResourceType_WMP, entry.name.to_owned())
+			{
+				for worldmap in wmp.worldmaps
+				{
+					let (connections, warnings) = worldmap.areaConnections();
+					for (from, to) in connections
+					{
+						graph.addEdge(from, to);
+					}
+					
+					for warning in warnings
+					{
+						graph.warnings.push(format!("{} in WMP resource {}", warning, entry.name));
+					}
+				}
+			}
+			else
+			{
+				graph.warnings.push(format!("Failed to load WMP resource {}", entry.name));
+			}
+		}
+	}
+	
 	/**
 	Swap the file extension between `.bif` and `.BIF`, depending on the given
 	file path.
@@ -54,6 +184,136 @@ impl ResourceManager
 		};
 	}
 	
+	/**
+	Build a graph of how all of a `game`'s areas connect to one another.
+	
+	## Parameters
+	
+	- **game** - The game which identifies the installation path from which to
+		read.
+	
+	## Usage
+	
+	```
+	use crate::{graph::AreaGraph, platform::Games, resources::ResourceManager};
+	
+	let resourceManager: ResourceManager = ResourceManager::default();
+	let graph: AreaGraph = resourceManager.buildAreaGraph(Games::BaldursGate1);
+	assert!(!graph.edges.is_empty());
+	```
+	
+	## Remarks
+	
+	Every ARE resource listed in the `game`'s `Key` is loaded and each of its
+	travel regions is added as an edge. Every WMP resource is then loaded and
+	each of its area links is added as an edge.
+	
+	Resources which fail to load are skipped and a message describing the
+	failure is added to the graph's `warnings`.
+	*/
+	pub fn buildAreaGraph(&self, game: Games) -> AreaGraph
+	{
+		let mut graph = AreaGraph::default();
+		
+		if let Some(key) = self.loadKey(game)
+		{
+			self.addTravelRegionEdges(game, &key, &mut graph);
+			self.addWorldmapLinkEdges(game, &key, &mut graph);
+		}
+		else
+		{
+			graph.warnings.push(format!("Failed to load the KEY file for {:?}", game));
+		}
+		
+		return graph;
+	}
+	
+	/**
+	Determine which game is installed in the given directory.
+	
+	## Parameters
+	
+	- **path** - The absolute path to a game's installation directory.
+	
+	## Usage
+	
+	```
+	use std::path::Path;
+	use crate::{platform::Games, resources::ResourceManager};
+	
+	let game: Option<Games> = ResourceManager::detectGame(Path::new("C:\\Games\\Baldur's Gate"));
+	assert_eq!(Some(Games::BaldursGate1), game);
+	```
+	
+	## Remarks
+	
+	A game is only considered a match when its KEY file and all of its signature
+	files are present. When more than one game matches, the game with the most
+	signature files is returned since it is the most specific match. If two or
+	more games tie for the most signature files, the installation is ambiguous
+	and `None` is returned. File names are compared case insensitively.
+	*/
+	pub fn detectGame(path: &Path) -> Option<Games>
+	{
+		let mut result = None;
+		let mut matchedCount = 0;
+		let mut ambiguous = false;
+		
+		for game in (0..).map_while(Games::from_repr).filter(|g| *g != Games::None)
+		{
+			let Some(keyFile) = KeyFileName(game) else { continue; };
+			let Some(signatures) = SignatureFiles(game) else { continue; };
+			
+			if Self::findFilePath(path, &keyFile).is_some()
+				&& signatures.len() >= matchedCount
+				&& signatures.iter().all(|s| Self::findFilePath(path, s).is_some())
+			{
+				ambiguous = signatures.len() == matchedCount;
+				matchedCount = signatures.len();
+				result = Some(game);
+			}
+		}
+		
+		return match ambiguous
+		{
+			true => None,
+			false => result,
+		};
+	}
+	
+	/**
+	Find a file within a directory, ignoring the case of each path component.
+	
+	## Parameters
+	
+	- **directory** - The directory in which to begin the search.
+	- **fileName** - The path, relative to `directory`, and file name.
+	
+	## Remarks
+	
+	Some platforms are case sensitive but the games were not developed with case
+	sensitivity in mind. So each component of `fileName` is compared against
+	the directory's entries without regard to case.
+	*/
+	fn findFilePath(directory: &Path, fileName: &str) -> Option<PathBuf>
+	{
+		let mut filePath = directory.to_path_buf();
+		for part in fileName.split("\\")
+		{
+			let exact = filePath.join(part);
+			filePath = match exact.exists()
+			{
+				true => exact,
+				false => filePath.read_dir().ok()?
+					.filter_map(|entry| entry.ok())
+					.find(|entry| entry.file_name().to_string_lossy().eq_ignore_ascii_case(part))?
+					.path(),
+			};
+		}
+		
+		return Some(filePath);
+	}
+	
 	/**
 	Generate a consistently formatted `PathBuf` instance based on the given file
 	name and game.
@@ -338,6 +598,41 @@ impl ResourceManager
 		return tilesetEntry.data.to_owned();
 	}
 	
+	/**
+	Load the tiles of a named `Tis` resource as RGBA pixel data.
+	
+	## Parameters
+	
+	- **game** - The game which identifies the installation path from which to
+		read.
+	- **resourceName** - The name of the resource to be loaded. Typically a
+		`RESREF` value.
+	
+	## Usage
+	
+	```
+	use crate::{platform::Games, resources::ResourceManager};
+	
+	let resourceManager: ResourceManager = ResourceManager::default();
+	let tiles: Option<Vec<Vec<u8>>> = resourceManager.loadTilesetTiles(Games::BaldursGate1, "AR2600".to_string());
+	assert!(tiles.is_some());
+	```
+	
+	## Remarks
+	
+	Each tile is converted via `TisTileData::toBytes` and returned in the order
+	in which it appears in the `Tis`. No WED layout is applied, making this
+	suitable for inspecting a tileset on its own.
+	*/
+	pub fn loadTilesetTiles(&self, game: Games, resourceName: String) -> Option<Vec<Vec<u8>>>
+	{
+		let tis = self.loadTileset(game, resourceName)?;
+		return Some(tis.tiles
+			.iter()
+			.map(|tile| tile.toBytes())
+			.collect());
+	}
+	
 	/**
 	Load a named `Tlk` file from a game's install directory.
 	
@@ -400,6 +695,32 @@ impl ResourceManager
 		return Some(self.tlks.borrow().get(&game)?.get(&fileName)?.to_owned());
 	}
 	
+	/**
+	Read a `game`'s BIF file without adding it to the cache.
+	
+	## Parameters
+	
+	- **game** - The game which identifies the installation path from which to
+		read.
+	- **fileName** - The path, relative to the installation directory, and file
+		name of the BIF file to read.
+	*/
+	fn readBifUncached(&self, game: Games, fileName: String) -> Result<Bif>
+	{
+		let filePath = self.formatFilePath(game, fileName.to_owned())
+			.context(format!("No installation path set for {:?}", game))?;
+		
+		return match ReadFromFile::<Bif>(filePath.as_path())
+		{
+			Ok(bif) => Ok(bif),
+			Err(e) => match self.alternateBifExtension(filePath)
+			{
+				Some(alternatePath) => ReadFromFile::<Bif>(alternatePath.as_path()),
+				None => Err(e),
+			},
+		};
+	}
+	
 	/**
 	Read a Bif file at the given file path and, if successful, cache the result.
 	
@@ -522,3 +843,100 @@ impl ResourceManager
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests
+{
+	use std::env;
+	use std::fs::{self, File};
+	use super::*;
+	use crate::test::updateResourceManager;
+	
+	fn fabricateInstall(name: &str, files: Vec<&str>) -> PathBuf
+	{
+		let path = env::temp_dir().join(format!("infinity-engine-parser_{}", name));
+		let _ = fs::remove_dir_all(&path);
+		
+		for file in files
+		{
+			let filePath = path.join(file);
+			if let Some(parent) = filePath.parent()
+			{
+				fs::create_dir_all(parent).unwrap();
+			}
+			File::create(filePath).unwrap();
+		}
+		
+		return path;
+	}
+	
+	#[test]
+	fn DetectGame()
+	{
+		let cases = vec![
+			( "bg1", vec![ "Chitin.key", "Baldur.ini", "data/Default.bif" ], Some(Games::BaldursGate1) ),
+			( "bg2", vec![ "CHITIN.KEY", "baldur.ini", "data/25Areas.bif" ], Some(Games::BaldursGate2) ),
+			( "bgee", vec![ "chitin.key", "engine.lua", "lang/en_US/dialog.tlk", "data/Area000A.bif" ], Some(Games::BaldursGate1EnhancedEdition) ),
+			( "bg2ee", vec![ "chitin.key", "engine.lua", "lang/en_US/dialog.tlk", "data/25areas.bif" ], Some(Games::BaldursGate2EnhancedEdition) ),
+			( "bgee+bg2ee", vec![ "chitin.key", "engine.lua", "lang/en_US/dialog.tlk", "data/Area000A.bif", "data/25Areas.bif" ], None ),
+			( "iwd2", vec![ "CHITIN.KEY", "Icewind2.ini" ], Some(Games::IcewindDale2) ),
+			( "nokey", vec![ "baldur.ini" ], None ),
+		];
+		
+		for (name, files, expected) in cases
+		{
+			let path = fabricateInstall(name, files);
+			let result = ResourceManager::detectGame(path.as_path());
+			let _ = fs::remove_dir_all(&path);
+			
+			assert_eq!(expected, result, "Detecting {}", name);
+		}
+	}
+	
+	#[test]
+	fn LoadTilesetTiles()
+	{
+		let game = Games::BaldursGate1;
+		let name = "AR2600".to_string();
+		
+		let resourceManager = ResourceManager::default();
+		let _ = updateResourceManager(&resourceManager);
+		
+		let tis = resourceManager.loadTileset(game, name.to_owned()).unwrap();
+		let result = resourceManager.loadTilesetTiles(game, name.to_owned()).unwrap();
+		
+		assert_eq!(tis.tileCount as usize, result.len());
+		for tile in result
+		{
+			assert_eq!((Tis::TileLength * Tis::ColorLength) as usize, tile.len());
+		}
+	}
+	
+	#[test]
+	fn BuildAreaGraph()
+	{
+		let game = Games::BaldursGate1;
+		
+		let resourceManager = ResourceManager::default();
+		let _ = updateResourceManager(&resourceManager);
+		
+		let result = resourceManager.buildAreaGraph(game);
+		
+		//Area BIFs are not left in the cache
+		let key = resourceManager.loadKey(game).unwrap();
+		let areaEntry = key.resourceEntries
+			.iter()
+			.find(|entry| entry.r#type == ResourceType_ARE as u16 && entry.name == "AR2300")
+			.unwrap();
+		let areaBif = key.bifEntries[areaEntry.indexBifEntry() as usize].fileName.to_owned();
+		assert!(!resourceManager.bifs.borrow().get(&game).is_some_and(|map| map.contains_key(&areaBif)));
+		
+		//Friendly Arm Inn to the inn's ground floor, via a travel region
+		assert!(result.containsEdge("AR2300".to_string(), "AR2301".to_string()));
+		
+		//Beregost to High Hedge, via WORLDMAP.WMP only
+		let beregost = resourceManager.loadResource::<Are>(game, ResourceType_ARE, "AR3300".to_string()).unwrap();
+		assert!(!beregost.travelDestinations().contains(&"AR3200".to_string()));
+		assert!(result.containsEdge("AR3300".to_string(), "AR3200".to_string()));
+	}
+}
@@ -77,6 +77,18 @@ impl Are
 		return Ok(explored);
 	}
 	
+	/**
+	The names of the areas to which this area's travel regions lead.
+	*/
+	pub fn travelDestinations(&self) -> Vec<String>
+	{
+		return self.regions
+			.iter()
+			.filter(|region| region.regionType == AreRegion::TypeTravel && !region.destination.is_empty())
+			.map(|region| region.destination.to_owned())
+			.collect();
+	}
+	
 	pub fn readWed(&mut self, resourceManager: &ResourceManager, game: Games)
 	{
 		self.wed = resourceManager.loadResource(game, ResourceType_WED, self.header.wedName.to_owned());
@@ -227,6 +227,7 @@ impl Readable for AreHeader
 		let fog = cursor.read_u16::<LittleEndian>()?;
 		let lightning = cursor.read_u16::<LittleEndian>()?;
 		let wind = cursor.read_u16::<LittleEndian>()?;
+		//Some sections store their count before their offset. See SectionAddress for details.
 		let actors = SectionAddress::<u32, u16>::fromCursor(cursor)?;
 		let regions = SectionAddress::<u32, u16>::fromCursorInverted(cursor)?;
 		let spawnPoints = SectionAddress::<u32, u32>::fromCursor(cursor)?;
@@ -291,3 +292,80 @@ impl Readable for AreHeader
 		});
 	}
 }
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use crate::platform::Games;
+	use crate::resource::ResourceManager;
+	use crate::types::ResourceType_ARE;
+	use crate::test::updateResourceManager;
+	
+	const HeaderSize: u64 = 0x11c;
+	
+	#[test]
+	fn SectionAddresses()
+	{
+		let game = Games::BaldursGate1;
+		let name = "AR2600";
+		
+		let resourceManager = ResourceManager::default();
+		let _ = updateResourceManager(&resourceManager);
+		
+		let key = resourceManager.loadKey(game).unwrap();
+		let resourceEntry = key.resourceEntries
+			.iter()
+			.find(|entry| entry.r#type == ResourceType_ARE as u16 && entry.name == name)
+			.unwrap();
+		let bif = resourceManager.loadBif(game, key.bifEntries[resourceEntry.indexBifEntry() as usize].fileName.to_owned()).unwrap();
+		let data = bif.fileEntries
+			.iter()
+			.find(|entry| entry.index() == resourceEntry.indexFile())
+			.unwrap()
+			.data
+			.clone();
+		let length = data.len() as u64;
+		
+		let mut cursor = Cursor::new(data);
+		let result = AreHeader::fromCursor(&mut cursor).unwrap();
+		
+		assert_eq!(HeaderSize, cursor.position());
+		assert_eq!(name, result.wedName);
+		
+		//The actors immediately follow the header
+		assert_eq!(HeaderSize, result.actors.offset as u64);
+		assert_ne!(0, result.regions.count);
+		assert_ne!(0, result.entrances.count);
+		assert_ne!(0, result.vertices.count);
+		
+		//A swapped offset and count would point into the header or past the end of the file
+		let sections: Vec<(&str, u64, u64)> = vec![
+			( "actors", result.actors.offset.into(), result.actors.count.into() ),
+			( "regions", result.regions.offset.into(), result.regions.count.into() ),
+			( "spawnPoints", result.spawnPoints.offset.into(), result.spawnPoints.count.into() ),
+			( "entrances", result.entrances.offset.into(), result.entrances.count.into() ),
+			( "containers", result.containers.offset.into(), result.containers.count.into() ),
+			( "items", result.items.offset.into(), result.items.count.into() ),
+			( "vertices", result.vertices.offset.into(), result.vertices.count.into() ),
+			( "ambients", result.ambients.offset.into(), result.ambients.count.into() ),
+			( "variables", result.variables.offset.into(), result.variables.count.into() ),
+			( "explored", result.explored.offset.into(), result.explored.size.into() ),
+			( "doors", result.doors.offset.into(), result.doors.count.into() ),
+			( "animations", result.animations.offset.into(), result.animations.count.into() ),
+			( "tiledObjects", result.tiledObjects.offset.into(), result.tiledObjects.count.into() ),
+			( "automapNotes", result.automapNotes.offset.into(), result.automapNotes.count.into() ),
+			( "projectileTraps", result.projectileTraps.offset.into(), result.projectileTraps.count.into() ),
+		];
+		
+		for (section, offset, count) in sections
+		{
+			if count > 0
+			{
+				assert!(offset >= HeaderSize, "Offset of ARE section {} is inside the header", section);
+				assert!(offset < length, "Offset of ARE section {} is past the end of the file", section);
+				assert!(count < length, "Count of ARE section {} exceeds the file length", section);
+			}
+		}
+	}
+}
@@ -71,6 +71,8 @@ pub struct AreRegion
 
 impl AreRegion
 {
+	pub const TypeTravel: u16 = 2;
+	
 	const UnknownSize: u64 = 36;
 }
 
@@ -66,6 +66,14 @@ impl Bmp
 		};
 	}
 	
+	/**
+	Borrow the encoded pixel data without copying it.
+	*/
+	pub fn encodedSlice(&self) -> &[u8]
+	{
+		return self.encoded.as_slice();
+	}
+	
 	pub fn toBytes(&self) -> Vec<u8>
 	{
 		let mut bytes = vec![];
@@ -350,6 +358,19 @@ mod tests
 	use crate::test::updateResourceManager;
 	use crate::types::ResourceType_BMP;
 	
+	#[test]
+	fn BmpEncodedSlice()
+	{
+		let width = 4;
+		let height = 2;
+		let bmp = Bmp::adhoc(width, height, vec![0; (width * height * 3) as usize], None);
+		
+		let result = bmp.encodedSlice();
+		
+		assert_eq!((width * height * 3) as usize, result.len());
+		assert_eq!(bmp.encoded.as_ptr(), result.as_ptr());
+	}
+	
 	#[test]
 	fn BmpTest()
 	{
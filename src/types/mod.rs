@@ -6,6 +6,7 @@ mod tis;
 mod tlk;
 mod util;
 mod wed;
+mod wmp;
 
 pub use are::Are;
 pub use bif::Bif;
@@ -13,6 +14,7 @@ pub use bmp::Bmp;
 pub use key::Key;
 pub use tis::Tis;
 pub use tlk::Tlk;
+pub use wmp::Wmp;
 pub use util::{TypeSize_RESREF, Dimensions, Identity, InfinityEngineType, Readable, ReadIntoSelf, ReadFromFile, ReadList};
 
 pub use bif::{
@@ -26,4 +28,5 @@ pub use bif::{
 	ResourceType_WAV,
 	ResourceType_WAVC,
 	ResourceType_WED,
+	ResourceType_WMP,
 };
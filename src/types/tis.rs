@@ -143,6 +143,16 @@ pub struct TisTileData
 
 impl TisTileData
 {
+	/**
+	Borrow the tile's pixel data without copying it.
+	
+	Each value is an index into the tile's palette.
+	*/
+	pub fn pixelSlice(&self) -> &[u8]
+	{
+		return self.pixels.as_slice();
+	}
+	
 	pub fn toBytes(&self) -> Vec<u8>
 	{
 		let chromaKey = self.colors[0];
@@ -208,6 +218,18 @@ mod tests
 	use crate::resource::ResourceManager;
 	use crate::test::updateResourceManager;
 	
+	#[test]
+	fn TestPixelSlice()
+	{
+		let data = vec![0; Tis::PaletteSize * Tis::ColorLength as usize + Tis::TileLength as usize];
+		let tile = TisTileData::fromCursor(&mut Cursor::new(data)).unwrap();
+		
+		let result = tile.pixelSlice();
+		
+		assert_eq!(Tis::TileLength as usize, result.len());
+		assert_eq!(tile.pixels.as_ptr(), result.as_ptr());
+	}
+	
     #[test]
     fn TestTis()
 	{
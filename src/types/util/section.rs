@@ -3,6 +3,41 @@ use ::anyhow::Result;
 use ::byteorder::{LittleEndian, ReadBytesExt};
 use super::Readable;
 
+/**
+The location of a section of data within a file, described by an offset from
+the start of the file and a count of the entries in that section.
+
+## Field Order
+
+Infinity Engine file formats are not consistent in the order in which these two
+values are stored. Most sections store the offset first, followed by the count,
+and are read with `fromCursor`. Some sections store the count first, followed by
+the offset, and are read with `fromCursorInverted`.
+
+The ARE header is a notable mix of both:
+
+Section | Order | Reader
+---|---|---
+Actors | Offset, Count | `fromCursor`
+Regions | Count, Offset | `fromCursorInverted`
+Spawn points | Offset, Count | `fromCursor`
+Entrances | Offset, Count | `fromCursor`
+Containers | Offset, Count | `fromCursor`
+Items | Count, Offset | `fromCursorInverted`
+Vertices | Offset, Count | `fromCursor`
+Ambients | Count, Offset | `fromCursorInverted`
+Variables | Offset, Count | `fromCursor`
+Tiled object flags | Offset, Count | `fromCursor`
+Doors | Count, Offset | `fromCursorInverted`
+Animations | Count, Offset | `fromCursorInverted`
+Tiled objects | Count, Offset | `fromCursorInverted`
+Automap notes | Offset, Count | `fromCursor`
+Projectile traps | Offset, Count | `fromCursor`
+
+The automap notes and projectile traps rows do not apply to PST, which stores
+0xFFFFFFFF followed by the automap notes offset and count, and has no projectile
+traps section. `AreHeader::fromCursor` does not handle the PST layout.
+*/
 #[derive(Clone, Copy, Debug, Default)]
 pub struct SectionAddress<A, B>
 	where A: Copy,
@@ -14,6 +49,10 @@ pub struct SectionAddress<A, B>
 
 impl SectionAddress<u16, u16>
 {
+	/**
+	Create a new instance from data stored with the count first, followed by
+	the offset.
+	*/
 	pub fn fromCursorInverted(cursor: &mut Cursor<Vec<u8>>) -> Result<Self>
 	{
 		let count = cursor.read_u16::<LittleEndian>()?;
@@ -44,6 +83,10 @@ impl Readable for SectionAddress<u16, u16>
 
 impl SectionAddress<u16, u32>
 {
+	/**
+	Create a new instance from data stored with the count first, followed by
+	the offset.
+	*/
 	pub fn fromCursorInverted(cursor: &mut Cursor<Vec<u8>>) -> Result<Self>
 	{
 		let count = cursor.read_u32::<LittleEndian>()?;
@@ -74,6 +117,10 @@ impl Readable for SectionAddress<u16, u32>
 
 impl SectionAddress<u32, u16>
 {
+	/**
+	Create a new instance from data stored with the count first, followed by
+	the offset.
+	*/
 	pub fn fromCursorInverted(cursor: &mut Cursor<Vec<u8>>) -> Result<Self>
 	{
 		let count = cursor.read_u16::<LittleEndian>()?;
@@ -104,6 +151,10 @@ impl Readable for SectionAddress<u32, u16>
 
 impl SectionAddress<u32, u32>
 {
+	/**
+	Create a new instance from data stored with the count first, followed by
+	the offset.
+	*/
 	pub fn fromCursorInverted(cursor: &mut Cursor<Vec<u8>>) -> Result<Self>
 	{
 		let count = cursor.read_u32::<LittleEndian>()?;
@@ -131,3 +182,109 @@ impl Readable for SectionAddress<u32, u32>
 		});
 	}
 }
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use crate::types::are::AreHeader;
+	
+	const AreHeaderSize: usize = 0x11c;
+	
+	/**
+	The ARE header sections, matching the Field Order table in the
+	`SectionAddress` documentation.
+	
+	( Name, Position in header, Inverted, Size of offset, Size of count )
+	*/
+	const AreSections: [(&str, usize, bool, usize, usize); 15] = [
+		( "actors", 0x0054, false, 4, 2 ),
+		( "regions", 0x005a, true, 4, 2 ),
+		( "spawnPoints", 0x0060, false, 4, 4 ),
+		( "entrances", 0x0068, false, 4, 4 ),
+		( "containers", 0x0070, false, 4, 2 ),
+		( "items", 0x0076, true, 4, 2 ),
+		( "vertices", 0x007c, false, 4, 2 ),
+		( "ambients", 0x0082, true, 4, 2 ),
+		( "variables", 0x0088, false, 4, 4 ),
+		( "tiledObjectFlags", 0x0090, false, 2, 2 ),
+		( "doors", 0x00a4, true, 4, 4 ),
+		( "animations", 0x00ac, true, 4, 4 ),
+		( "tiledObjects", 0x00b4, true, 4, 4 ),
+		( "automapNotes", 0x00c4, false, 4, 4 ),
+		( "projectileTraps", 0x00cc, false, 4, 4 ),
+	];
+	
+	fn expectedOffset(index: usize) -> u64
+	{
+		return 0x0100 + index as u64;
+	}
+	
+	fn expectedCount(index: usize) -> u64
+	{
+		return 1 + index as u64;
+	}
+	
+	fn headerSection(header: &AreHeader, name: &str) -> (u64, u64)
+	{
+		return match name
+		{
+			"actors" => (header.actors.offset.into(), header.actors.count.into()),
+			"regions" => (header.regions.offset.into(), header.regions.count.into()),
+			"spawnPoints" => (header.spawnPoints.offset.into(), header.spawnPoints.count.into()),
+			"entrances" => (header.entrances.offset.into(), header.entrances.count.into()),
+			"containers" => (header.containers.offset.into(), header.containers.count.into()),
+			"items" => (header.items.offset.into(), header.items.count.into()),
+			"vertices" => (header.vertices.offset.into(), header.vertices.count.into()),
+			"ambients" => (header.ambients.offset.into(), header.ambients.count.into()),
+			"variables" => (header.variables.offset.into(), header.variables.count.into()),
+			"tiledObjectFlags" => (header.tiledObjectFlags.offset.into(), header.tiledObjectFlags.count.into()),
+			"doors" => (header.doors.offset.into(), header.doors.count.into()),
+			"animations" => (header.animations.offset.into(), header.animations.count.into()),
+			"tiledObjects" => (header.tiledObjects.offset.into(), header.tiledObjects.count.into()),
+			"automapNotes" => (header.automapNotes.offset.into(), header.automapNotes.count.into()),
+			"projectileTraps" => (header.projectileTraps.offset.into(), header.projectileTraps.count.into()),
+			_ => panic!("Unknown ARE section: {}", name),
+		};
+	}
+	
+	#[test]
+	fn AreSectionOrder()
+	{
+		//Write each section in its documented order
+		let mut data = vec![0; AreHeaderSize];
+		for (index, (_, position, inverted, offsetSize, countSize)) in AreSections.iter().enumerate()
+		{
+			let offset = &expectedOffset(index).to_le_bytes()[..*offsetSize];
+			let count = &expectedCount(index).to_le_bytes()[..*countSize];
+			let bytes = match inverted
+			{
+				true => [count, offset].concat(),
+				false => [offset, count].concat(),
+			};
+			
+			data[*position..*position + bytes.len()].copy_from_slice(&bytes);
+		}
+		
+		let header = AreHeader::fromCursor(&mut Cursor::new(data)).unwrap();
+		
+		for (index, (name, ..)) in AreSections.iter().enumerate()
+		{
+			let (offset, count) = headerSection(&header, name);
+			assert_eq!(expectedOffset(index), offset, "Offset of ARE section {}", name);
+			assert_eq!(expectedCount(index), count, "Count of ARE section {}", name);
+		}
+	}
+	
+	#[test]
+	fn InvertedReadsCountFirst()
+	{
+		let data = [1u32.to_le_bytes(), 2u32.to_le_bytes()].concat();
+		
+		let normal = SectionAddress::<u32, u32>::fromCursor(&mut Cursor::new(data.clone())).unwrap();
+		let inverted = SectionAddress::<u32, u32>::fromCursorInverted(&mut Cursor::new(data)).unwrap();
+		
+		assert_eq!((1, 2), (normal.offset, normal.count));
+		assert_eq!((2, 1), (inverted.offset, inverted.count));
+	}
+}
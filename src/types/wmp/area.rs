@@ -0,0 +1,185 @@
+use std::io::Cursor;
+use ::anyhow::Result;
+use ::byteorder::{LittleEndian, ReadBytesExt};
+use crate::bytes::{readName, readResRef};
+use crate::types::util::{Readable, SectionAddress};
+
+/**
+The fully parsed contents of an Area entry in a WMP file.
+
+See https://gibberlings3.github.io/iesdp/file_formats/ie_formats/wmap_v1.htm
+
+---
+
+Offset | Size | Description
+---|---|---
+0x0000 | 8 | Area resref
+0x0008 | 8 | Area name (short)
+0x0010 | 32 | Area name (long)
+0x0030 | 4 | Status bitmask
+0x0034 | 4 | BAM file sequence (icons)
+0x0038 | 4 | X coordinate
+0x003c | 4 | Y coordinate
+0x0040 | 4 | Name (caption)
+0x0044 | 4 | Name (tooltips)
+0x0048 | 8 | Loading screen MOS file
+0x0050 | 4 | Index of first link (north)
+0x0054 | 4 | Count of links (north)
+0x0058 | 4 | Index of first link (west)
+0x005c | 4 | Count of links (west)
+0x0060 | 4 | Index of first link (south)
+0x0064 | 4 | Count of links (south)
+0x0068 | 4 | Index of first link (east)
+0x006c | 4 | Count of links (east)
+0x0070 | 128 | Unused
+
+---
+
+### Link Indices
+
+The link indices are relative to the parent worldmap's list of area links, not
+offsets from the start of the file. They are stored in the `offset` field of
+each `SectionAddress`.
+*/
+#[derive(Clone, Debug, Default)]
+pub struct WmpArea
+{
+	pub resref: String,
+	pub shortName: String,
+	pub longName: String,
+	pub status: u32,
+	pub bamSequence: u32,
+	pub x: u32,
+	pub y: u32,
+	pub caption: u32,
+	pub tooltip: u32,
+	pub loadingScreen: String,
+	pub linksNorth: SectionAddress<u32, u32>,
+	pub linksWest: SectionAddress<u32, u32>,
+	pub linksSouth: SectionAddress<u32, u32>,
+	pub linksEast: SectionAddress<u32, u32>,
+}
+
+impl WmpArea
+{
+	const UnusedPadding: u64 = 128;
+	
+	/**
+	The direction names of the link sections, in the order returned by
+	`linkSections`.
+	*/
+	pub const Directions: [&'static str; 4] = ["north", "west", "south", "east"];
+	
+	/**
+	Indices of the link sections whose range extends past the end of the
+	parent worldmap's list of area links.
+	
+	## Parameters
+	
+	- **linkCount** - The number of area links in the parent worldmap.
+	*/
+	pub fn invalidLinkSections(&self, linkCount: usize) -> Vec<usize>
+	{
+		return self.linkSections()
+			.iter()
+			.enumerate()
+			.filter(|(_, links)| (links.offset as usize).saturating_add(links.count as usize) > linkCount)
+			.map(|(i, _)| i)
+			.collect();
+	}
+	
+	/**
+	The indices of all links leaving this area, in north, west, south, east
+	order.
+	
+	## Parameters
+	
+	- **linkCount** - The number of area links in the parent worldmap. Each
+		range is clamped to this value so malformed data cannot produce indices
+		which do not exist.
+	*/
+	pub fn linkIndices(&self, linkCount: usize) -> Vec<usize>
+	{
+		return self.linkSections()
+			.iter()
+			.flat_map(|links| {
+				let start = (links.offset as usize).min(linkCount);
+				let end = start.saturating_add(links.count as usize).min(linkCount);
+				start..end
+			})
+			.collect();
+	}
+	
+	/**
+	The link sections in north, west, south, east order.
+	*/
+	pub fn linkSections(&self) -> [SectionAddress<u32, u32>; 4]
+	{
+		return [self.linksNorth, self.linksWest, self.linksSouth, self.linksEast];
+	}
+}
+
+impl Readable for WmpArea
+{
+	fn fromCursor(cursor: &mut Cursor<Vec<u8>>) -> Result<Self>
+	{
+		let resref = readResRef(cursor)?;
+		let shortName = readResRef(cursor)?;
+		let longName = readName(cursor)?;
+		let status = cursor.read_u32::<LittleEndian>()?;
+		let bamSequence = cursor.read_u32::<LittleEndian>()?;
+		let x = cursor.read_u32::<LittleEndian>()?;
+		let y = cursor.read_u32::<LittleEndian>()?;
+		let caption = cursor.read_u32::<LittleEndian>()?;
+		let tooltip = cursor.read_u32::<LittleEndian>()?;
+		let loadingScreen = readResRef(cursor)?;
+		let linksNorth = SectionAddress::<u32, u32>::fromCursor(cursor)?;
+		let linksWest = SectionAddress::<u32, u32>::fromCursor(cursor)?;
+		let linksSouth = SectionAddress::<u32, u32>::fromCursor(cursor)?;
+		let linksEast = SectionAddress::<u32, u32>::fromCursor(cursor)?;
+		
+		cursor.set_position(cursor.position() + Self::UnusedPadding);
+		
+		return Ok(Self
+		{
+			resref,
+			shortName,
+			longName,
+			status,
+			bamSequence,
+			x,
+			y,
+			caption,
+			tooltip,
+			loadingScreen,
+			linksNorth,
+			linksWest,
+			linksSouth,
+			linksEast,
+		});
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	
+	#[test]
+	fn LinkIndicesClamped()
+	{
+		let linkCount = 3;
+		let mut area = WmpArea::default();
+		area.linksNorth.offset = 1;
+		area.linksNorth.count = u32::MAX;
+		area.linksWest.offset = u32::MAX;
+		area.linksWest.count = u32::MAX;
+		area.linksEast.offset = 0;
+		area.linksEast.count = 1;
+		
+		let result = area.linkIndices(linkCount);
+		
+		assert_eq!(vec![1, 2, 0], result);
+		assert_eq!(vec![0, 1], area.invalidLinkSections(linkCount));
+	}
+}
@@ -0,0 +1,75 @@
+use std::io::Cursor;
+use ::anyhow::Result;
+use ::byteorder::{LittleEndian, ReadBytesExt};
+use crate::bytes::{readName, readResRef};
+use crate::types::util::Readable;
+
+/**
+The fully parsed contents of an Area Link entry in a WMP file.
+
+See https://gibberlings3.github.io/iesdp/file_formats/ie_formats/wmap_v1.htm
+
+---
+
+Offset | Size | Description
+---|---|---
+0x0000 | 4 | Index of destination area
+0x0004 | 32 | Entry point
+0x0024 | 4 | Travel time / 4
+0x0028 | 4 | Default entry location
+0x002c | 8 | Random encounter area 1
+0x0034 | 8 | Random encounter area 2
+0x003c | 8 | Random encounter area 3
+0x0044 | 8 | Random encounter area 4
+0x004c | 8 | Random encounter area 5
+0x0054 | 4 | Random encounter probability
+0x0058 | 128 | Unused
+*/
+#[derive(Clone, Debug, Default)]
+pub struct WmpLink
+{
+	pub destination: u32,
+	pub entryPoint: String,
+	pub travelTime: u32,
+	pub defaultEntry: u32,
+	pub encounterAreas: Vec<String>,
+	pub encounterProbability: u32,
+}
+
+impl WmpLink
+{
+	const EncounterAreaCount: usize = 5;
+	const UnusedPadding: u64 = 128;
+}
+
+impl Readable for WmpLink
+{
+	fn fromCursor(cursor: &mut Cursor<Vec<u8>>) -> Result<Self>
+	{
+		let destination = cursor.read_u32::<LittleEndian>()?;
+		let entryPoint = readName(cursor)?;
+		let travelTime = cursor.read_u32::<LittleEndian>()?;
+		let defaultEntry = cursor.read_u32::<LittleEndian>()?;
+		
+		let mut encounterAreas = vec![];
+		for _ in 0..Self::EncounterAreaCount
+		{
+			let area = readResRef(cursor)?;
+			encounterAreas.push(area);
+		}
+		
+		let encounterProbability = cursor.read_u32::<LittleEndian>()?;
+		
+		cursor.set_position(cursor.position() + Self::UnusedPadding);
+		
+		return Ok(Self
+		{
+			destination,
+			entryPoint,
+			travelTime,
+			defaultEntry,
+			encounterAreas,
+			encounterProbability,
+		});
+	}
+}
@@ -0,0 +1,9 @@
+mod area;
+mod link;
+mod wmp;
+mod worldmap;
+
+pub use area::WmpArea;
+pub use link::WmpLink;
+pub use wmp::Wmp;
+pub use worldmap::WmpWorldmap;
@@ -0,0 +1,146 @@
+use std::io::Cursor;
+use ::anyhow::{Context, Result};
+use ::byteorder::{LittleEndian, ReadBytesExt};
+use crate::types::{InfinityEngineType, Identity, Readable, ReadList};
+use super::{WmpArea, WmpLink, WmpWorldmap};
+
+/**
+The fully parsed contents of a WMP file.
+
+See https://gibberlings3.github.io/iesdp/file_formats/ie_formats/wmap_v1.htm
+
+This file format describes the top-level map of the world for the player to
+travel around. It lists each area that can be traveled to and the links between
+those areas.
+
+---
+
+### Header Data
+
+Offset | Size | Description
+---|---|---
+0x0000 | 4 | Signature ('WMAP')
+0x0004 | 4 | Version ('V1.0')
+0x0008 | 4 | Count of worldmap entries
+0x000c | 4 | Offset to worldmap entries
+*/
+#[derive(Clone, Debug, Default)]
+pub struct Wmp
+{
+	pub identity: Identity,
+	pub worldmapCount: u32,
+	pub worldmapOffset: u32,
+	pub worldmaps: Vec<WmpWorldmap>,
+}
+
+impl Wmp
+{
+	pub const Signature: &'static str = "WMAP";
+	pub const Version: &'static str = "V1.0";
+}
+
+impl InfinityEngineType for Wmp {}
+
+impl Readable for Wmp
+{
+	fn fromCursor(cursor: &mut Cursor<Vec<u8>>) -> Result<Self>
+	{
+		let identity = Identity::fromCursor(cursor)?;
+		let worldmapCount = cursor.read_u32::<LittleEndian>()?;
+		let worldmapOffset = cursor.read_u32::<LittleEndian>()?;
+		
+		let mut worldmaps = ReadList::<WmpWorldmap>(cursor, worldmapOffset.into(), worldmapCount.into())
+			.context("Error parsing WMP worldmap entries")?;
+		
+		for worldmap in worldmaps.iter_mut()
+		{
+			worldmap.areas = ReadList::<WmpArea>(cursor, worldmap.areaEntries.offset.into(), worldmap.areaEntries.count.into())
+				.context(format!("Error parsing WMP area entries for map {}", worldmap.mapNumber))?;
+			worldmap.links = ReadList::<WmpLink>(cursor, worldmap.linkEntries.offset.into(), worldmap.linkEntries.count.into())
+				.context(format!("Error parsing WMP area link entries for map {}", worldmap.mapNumber))?;
+		}
+		
+		return Ok(Self
+		{
+			identity,
+			worldmapCount,
+			worldmapOffset,
+			worldmaps,
+		});
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use crate::platform::Games;
+	use crate::resource::ResourceManager;
+	use crate::types::ResourceType_WMP;
+	use crate::test::updateResourceManager;
+	
+	#[test]
+	fn FabricatedWmp()
+	{
+		//Header (16) + Worldmap (184) + 2 Areas (240) + 1 Link (216)
+		let mut data = vec![0u8; 16 + 184 + 480 + 216];
+		data[..8].copy_from_slice(b"WMAPV1.0");
+		data[0x08..0x0c].copy_from_slice(&1u32.to_le_bytes());
+		data[0x0c..0x10].copy_from_slice(&16u32.to_le_bytes());
+		
+		//Worldmap: area count, area offset, link offset, link count
+		data[16 + 0x20..16 + 0x24].copy_from_slice(&2u32.to_le_bytes());
+		data[16 + 0x24..16 + 0x28].copy_from_slice(&200u32.to_le_bytes());
+		data[16 + 0x28..16 + 0x2c].copy_from_slice(&680u32.to_le_bytes());
+		data[16 + 0x2c..16 + 0x30].copy_from_slice(&1u32.to_le_bytes());
+		
+		//First area links east to the second area
+		data[200..206].copy_from_slice(b"AR2600");
+		data[200 + 0x68..200 + 0x6c].copy_from_slice(&0u32.to_le_bytes());
+		data[200 + 0x6c..200 + 0x70].copy_from_slice(&1u32.to_le_bytes());
+		data[440..446].copy_from_slice(b"AR2700");
+		data[680..684].copy_from_slice(&1u32.to_le_bytes());
+		data[684..688].copy_from_slice(b"Exit");
+		
+		let result = Wmp::fromCursor(&mut Cursor::new(data)).unwrap();
+		
+		assert_eq!(Wmp::Signature, result.identity.signature);
+		assert_eq!(1, result.worldmaps.len());
+		
+		let worldmap = &result.worldmaps[0];
+		assert_eq!(2, worldmap.areas.len());
+		assert_eq!(1, worldmap.links.len());
+		assert_eq!("AR2600", worldmap.areas[0].resref);
+		assert_eq!("AR2700", worldmap.areas[1].resref);
+		assert_eq!(vec![0], worldmap.areas[0].linkIndices(worldmap.links.len()));
+		assert!(worldmap.areas[1].linkIndices(worldmap.links.len()).is_empty());
+		assert_eq!(1, worldmap.links[0].destination);
+		assert_eq!("Exit", worldmap.links[0].entryPoint);
+	}
+	
+	#[test]
+	fn ParseWmp()
+	{
+		let game = Games::BaldursGate1;
+		let name = "WORLDMAP";
+		
+		let resourceManager = ResourceManager::default();
+		let _ = updateResourceManager(&resourceManager);
+		
+		let result = resourceManager.loadResource::<Wmp>(game, ResourceType_WMP, name.to_owned()).unwrap();
+		
+		assert_eq!(Wmp::Signature, result.identity.signature);
+		assert_eq!(Wmp::Version, result.identity.version);
+		assert_eq!(result.worldmapCount as usize, result.worldmaps.len());
+		
+		for worldmap in result.worldmaps
+		{
+			assert_eq!(worldmap.areaEntries.count as usize, worldmap.areas.len());
+			assert_eq!(worldmap.linkEntries.count as usize, worldmap.links.len());
+			
+			let (connections, warnings) = worldmap.areaConnections();
+			assert!(!connections.is_empty());
+			assert!(warnings.is_empty(), "{:?}", warnings);
+		}
+	}
+}
@@ -0,0 +1,163 @@
+use std::io::Cursor;
+use ::anyhow::Result;
+use ::byteorder::{LittleEndian, ReadBytesExt};
+use crate::bytes::readResRef;
+use crate::types::util::{Readable, SectionAddress};
+use super::{WmpArea, WmpLink};
+
+/**
+The fully parsed contents of a Worldmap entry in a WMP file.
+
+See https://gibberlings3.github.io/iesdp/file_formats/ie_formats/wmap_v1.htm
+
+---
+
+Offset | Size | Description
+---|---|---
+0x0000 | 8 | Background image MOS file
+0x0008 | 4 | Width
+0x000c | 4 | Height
+0x0010 | 4 | Map number
+0x0014 | 4 | Area name
+0x0018 | 4 | Start centered on X
+0x001c | 4 | Start centered on Y
+0x0020 | 4 | Count of area entries
+0x0024 | 4 | Offset to area entries
+0x0028 | 4 | Offset to area link entries
+0x002c | 4 | Count of area link entries
+0x0030 | 8 | Map icons BAM file
+0x0038 | 4 | Flags (BGEE)
+0x003c | 124 | Unused
+*/
+#[derive(Clone, Debug, Default)]
+pub struct WmpWorldmap
+{
+	pub background: String,
+	pub width: u32,
+	pub height: u32,
+	pub mapNumber: u32,
+	pub areaName: u32,
+	pub startX: u32,
+	pub startY: u32,
+	pub areaEntries: SectionAddress<u32, u32>,
+	pub linkEntries: SectionAddress<u32, u32>,
+	pub mapIcons: String,
+	pub flags: u32,
+	pub areas: Vec<WmpArea>,
+	pub links: Vec<WmpLink>,
+}
+
+impl WmpWorldmap
+{
+	const UnusedPadding: u64 = 124;
+	
+	/**
+	Collect the connections between this worldmap's areas.
+	
+	Returns a list of `(from, to)` area RESREF pairs along with a list of
+	messages describing any link data which could not be resolved.
+	
+	## Remarks
+	
+	Each area's link sections are clamped to the number of area links so that
+	malformed data produces at most one message per link section and one per
+	link, rather than one per index described by the file.
+	*/
+	pub fn areaConnections(&self) -> (Vec<(String, String)>, Vec<String>)
+	{
+		let mut connections = vec![];
+		let mut warnings = vec![];
+		
+		for area in self.areas.iter()
+		{
+			for i in area.invalidLinkSections(self.links.len())
+			{
+				let links = area.linkSections()[i];
+				warnings.push(format!("Invalid {} area links (first {}, count {}) from {}; only {} links exist",
+					WmpArea::Directions[i], links.offset, links.count, area.resref, self.links.len()));
+			}
+			
+			for index in area.linkIndices(self.links.len())
+			{
+				if let Some(destination) = self.areas.get(self.links[index].destination as usize)
+				{
+					connections.push((area.resref.to_owned(), destination.resref.to_owned()));
+				}
+				else
+				{
+					warnings.push(format!("Invalid area link {} from {} to area index {}", index, area.resref, self.links[index].destination));
+				}
+			}
+		}
+		
+		return (connections, warnings);
+	}
+}
+
+impl Readable for WmpWorldmap
+{
+	fn fromCursor(cursor: &mut Cursor<Vec<u8>>) -> Result<Self>
+	{
+		let background = readResRef(cursor)?;
+		let width = cursor.read_u32::<LittleEndian>()?;
+		let height = cursor.read_u32::<LittleEndian>()?;
+		let mapNumber = cursor.read_u32::<LittleEndian>()?;
+		let areaName = cursor.read_u32::<LittleEndian>()?;
+		let startX = cursor.read_u32::<LittleEndian>()?;
+		let startY = cursor.read_u32::<LittleEndian>()?;
+		let areaEntries = SectionAddress::<u32, u32>::fromCursorInverted(cursor)?;
+		let linkEntries = SectionAddress::<u32, u32>::fromCursor(cursor)?;
+		let mapIcons = readResRef(cursor)?;
+		let flags = cursor.read_u32::<LittleEndian>()?;
+		
+		cursor.set_position(cursor.position() + Self::UnusedPadding);
+		
+		return Ok(Self
+		{
+			background,
+			width,
+			height,
+			mapNumber,
+			areaName,
+			startX,
+			startY,
+			areaEntries,
+			linkEntries,
+			mapIcons,
+			flags,
+			..Default::default()
+		});
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	
+	#[test]
+	fn AreaConnectionsBounded()
+	{
+		let mut first = WmpArea { resref: "AR2600".to_string(), ..Default::default() };
+		first.linksNorth.count = u32::MAX;
+		first.linksSouth.offset = u32::MAX;
+		first.linksSouth.count = u32::MAX;
+		let second = WmpArea { resref: "AR2700".to_string(), ..Default::default() };
+		
+		let worldmap = WmpWorldmap
+		{
+			areas: vec![first, second],
+			links: vec![
+				WmpLink { destination: 1, ..Default::default() },
+				WmpLink { destination: u32::MAX, ..Default::default() },
+			],
+			..Default::default()
+		};
+		
+		let (connections, warnings) = worldmap.areaConnections();
+		
+		assert_eq!(vec![("AR2600".to_string(), "AR2700".to_string())], connections);
+		//One per invalid link section, plus one for the link to a missing area
+		assert_eq!(3, warnings.len());
+	}
+}